@@ -1,11 +1,31 @@
-use minifb::{Key, Scale, ScaleMode, Window, WindowOptions};
+use minifb::{Key, MouseButton, MouseMode, Scale, ScaleMode, Window, WindowOptions};
 use rand::Rng;
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
 const GRID_W: usize = 120;
 const GRID_H: usize = 120;
 
+// Tamaño de la simulación, desacoplado del tamaño de ventana: con la cámara
+// (pan/zoom) solo una porción de esta grilla toroidal se blitea a la vez.
+const SIM_W: usize = 1024;
+const SIM_H: usize = 1024;
+
+const ZOOM_MIN: usize = 1;
+const ZOOM_MAX: usize = 8;
+
+// Checkpoint que se restaura automáticamente al iniciar, si existe.
+const DEFAULT_STATE_PATH: &str = "gol_state.sav";
+
+// Patrón RLE que se importa al iniciar (si no hay checkpoint) en vez de la
+// demo hardcodeada, y que F6/F7 guardan/recargan en tiempo de ejecución.
+const DEFAULT_RLE_PATH: &str = "pattern.rle";
+const RLE_SPAWN_OX: usize = 10;
+const RLE_SPAWN_OY: usize = 10;
+
 // Si tu versión de minifb no soporta X2, cambia a X1.
 const WINDOW_SCALE: Scale = Scale::X2;
 
@@ -13,6 +33,36 @@ const WINDOW_SCALE: Scale = Scale::X2;
 const DEAD: u32 = 0xFF000000;
 const ALIVE: u32 = 0xFFFFFFFF;
 
+// ==================== Coordenadas ventana -> grilla ====================
+
+/// Convierte una posición del mouse en píxeles *sin escalar* (tal como la
+/// entrega `Window::get_unscaled_mouse_pos`, no `get_mouse_pos` que ya
+/// divide por `WINDOW_SCALE`) a una celda `(x, y)` de la grilla. Divide por
+/// el ratio completo entre el tamaño real de la ventana y `grid_w`/`grid_h`,
+/// que incluye tanto `WINDOW_SCALE` como el estiramiento extra que
+/// `ScaleMode::Stretch` introduce si la ventana fue redimensionada.
+/// Devuelve `None` si el cursor cae fuera de la grilla.
+fn xy_to_world(
+    mx: f32,
+    my: f32,
+    win_w: usize,
+    win_h: usize,
+    grid_w: usize,
+    grid_h: usize,
+) -> Option<(usize, usize)> {
+    let stretch_x = win_w as f32 / grid_w as f32;
+    let stretch_y = win_h as f32 / grid_h as f32;
+
+    let gx = (mx / stretch_x).floor();
+    let gy = (my / stretch_y).floor();
+
+    if gx < 0.0 || gy < 0.0 || gx >= grid_w as f32 || gy >= grid_h as f32 {
+        return None;
+    }
+
+    Some((gx as usize, gy as usize))
+}
+
 // ==================== Framebuffer ====================
 
 struct Framebuffer {
@@ -57,6 +107,76 @@ impl Framebuffer {
     }
 }
 
+// ==================== Reglas (B/S) ====================
+
+/// Tabla de transición de un autómata tipo Life: para cada cantidad de
+/// vecinos vivos (0..=8), indica si una célula muerta nace o si una célula
+/// viva sobrevive.
+struct Rule {
+    birth: [bool; 9],
+    survive: [bool; 9],
+}
+
+impl Rule {
+    /// Parsea notación `B<digitos>/S<digitos>`, p. ej. `"B3/S23"` (Conway),
+    /// `"B36/S23"` (HighLife) o `"B2/S"` (Seeds). Devuelve `None` si el
+    /// string no tiene la forma `B.../S...`.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (b_part, s_part) = s.split_once('/')?;
+        let b_digits = b_part.strip_prefix('B')?;
+        let s_digits = s_part.strip_prefix('S')?;
+
+        let mut birth = [false; 9];
+        for ch in b_digits.chars() {
+            let n = ch.to_digit(10)? as usize;
+            if n > 8 {
+                return None;
+            }
+            birth[n] = true;
+        }
+
+        let mut survive = [false; 9];
+        for ch in s_digits.chars() {
+            let n = ch.to_digit(10)? as usize;
+            if n > 8 {
+                return None;
+            }
+            survive[n] = true;
+        }
+
+        Some(Self { birth, survive })
+    }
+
+    /// B3/S23: el Juego de la Vida clásico de Conway.
+    fn conway() -> Self {
+        Self::parse("B3/S23").unwrap()
+    }
+
+    /// Inversa de `parse`: reconstruye la notación `B.../S...` a partir de
+    /// las tablas de transición, para poder persistir la regla activa.
+    fn to_notation(&self) -> String {
+        let digits = |table: &[bool; 9]| -> String {
+            table
+                .iter()
+                .enumerate()
+                .filter(|&(_, &on)| on)
+                .map(|(n, _)| n.to_string())
+                .collect()
+        };
+        format!("B{}/S{}", digits(&self.birth), digits(&self.survive))
+    }
+}
+
+/// Presets (nombre, notación) para ciclar en tiempo de ejecución.
+const RULE_PRESETS: &[(&str, &str)] = &[
+    ("Conway", "B3/S23"),
+    ("HighLife", "B36/S23"),
+    ("Seeds", "B2/S"),
+    ("Day & Night", "B3678/S34678"),
+    ("Life without Death", "B3/S012345678"),
+];
+
 // ==================== Game of Life Core ====================
 
 struct GameOfLife {
@@ -67,8 +187,13 @@ struct GameOfLife {
     paused: bool,
     step_once: bool,
     delay_ms: u64,
+    rule: Rule,
+    history: VecDeque<Vec<u8>>,
 }
 
+/// Cuántas generaciones pasadas se conservan para poder deshacer con `step_back`.
+const HISTORY_CAP: usize = 50;
+
 impl GameOfLife {
     fn new(w: usize, h: usize) -> Self {
         Self {
@@ -79,6 +204,8 @@ impl GameOfLife {
             paused: false,
             step_once: false,
             delay_ms: 100,
+            rule: Rule::conway(),
+            history: VecDeque::new(),
         }
     }
 
@@ -136,19 +263,37 @@ impl GameOfLife {
         count
     }
 
-    /// Aplica las reglas de Conway.
+    /// Aplica la regla activa (`self.rule`), registrando el estado previo en el
+    /// historial para que `step_back` pueda deshacerlo. Una vez que el
+    /// historial alcanza `HISTORY_CAP`, reutiliza el buffer del snapshot más
+    /// viejo en vez de pedir memoria nueva en cada generación.
     fn step(&mut self) {
+        let snapshot = if self.history.len() >= HISTORY_CAP {
+            let mut reused = self.history.pop_front().unwrap();
+            reused.copy_from_slice(&self.curr);
+            reused
+        } else {
+            self.curr.clone()
+        };
+        self.history.push_back(snapshot);
+
+        self.step_no_history();
+    }
+
+    /// Igual que `step`, pero sin clonar `curr` al historial. Para
+    /// evaluaciones headless (p. ej. la búsqueda genética) que descartan la
+    /// grilla entera al terminar, llevar el historial de deshacer sería
+    /// trabajo tirado: cientos de miles de clones por corrida de evolución.
+    fn step_no_history(&mut self) {
         for y in 0..self.h {
             for x in 0..self.w {
                 let alive = self.curr[self.idx(x, y)] == 1;
                 let n = self.live_neighbors(x, y);
 
-                let new_state = match (alive, n) {
-                    (true, n) if n < 2 => 0,           // underpopulation
-                    (true, 2) | (true, 3) => 1,        // survival
-                    (true, n) if n > 3 => 0,           // overpopulation
-                    (false, 3) => 1,                   // reproduction
-                    _ => 0,
+                let new_state = if alive {
+                    self.rule.survive[n as usize] as u8
+                } else {
+                    self.rule.birth[n as usize] as u8
                 };
 
                 let i = self.idx(x, y);
@@ -158,12 +303,40 @@ impl GameOfLife {
         std::mem::swap(&mut self.curr, &mut self.next);
     }
 
-    /// Dibuja el estado actual en el framebuffer.
-    fn render_to(&self, fb: &mut Framebuffer) {
-        for y in 0..self.h {
-            for x in 0..self.w {
-                let color = if self.is_alive(x, y) { ALIVE } else { DEAD };
-                fb.point(x, y, color);
+    /// Retrocede un paso sacando el estado previo del historial (en vez de
+    /// avanzar con `step`). Devuelve `false` si no queda historial.
+    fn step_back(&mut self) -> bool {
+        match self.history.pop_back() {
+            Some(prev) => {
+                self.curr = prev;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Dibuja en el framebuffer solo la ventana visible de la grilla: el
+    /// rectángulo que arranca en `(cam_x, cam_y)` y cuyo tamaño en celdas
+    /// depende de `zoom` (cada celda ocupa un bloque `zoom x zoom` de
+    /// píxeles). Envuelve con `% self.w`/`% self.h`, así que panear más allá
+    /// del borde muestra el toroide de forma continua.
+    fn render_to(&self, fb: &mut Framebuffer, cam_x: usize, cam_y: usize, zoom: usize) {
+        let cells_w = fb.w.div_ceil(zoom);
+        let cells_h = fb.h.div_ceil(zoom);
+
+        for cy in 0..cells_h {
+            let wy = (cam_y + cy) % self.h;
+            for cx in 0..cells_w {
+                let wx = (cam_x + cx) % self.w;
+                let color = if self.is_alive(wx, wy) { ALIVE } else { DEAD };
+
+                let px0 = cx * zoom;
+                let py0 = cy * zoom;
+                for dy in 0..zoom {
+                    for dx in 0..zoom {
+                        fb.point(px0 + dx, py0 + dy, color);
+                    }
+                }
             }
         }
     }
@@ -392,13 +565,388 @@ impl GameOfLife {
             self.curr[i] = 1;
         }
     }
+
+    // ====== Formato RLE estándar ======
+
+    /// Carga un patrón en formato RLE (`x = W, y = H, rule = ...` + stream de
+    /// tokens) y lo estampa en `curr` con origen `(ox, oy)`, envolviendo con
+    /// el mismo `% w`/`% h` que usa `spawn`. El encabezado y los comentarios
+    /// `#` son informativos: la extensión real la define el propio stream.
+    fn load_rle(&mut self, ox: usize, oy: usize, path: &str) -> io::Result<()> {
+        let data = fs::read_to_string(path)?;
+        let mut lines = data.lines();
+
+        // Saltar comentarios y quedarnos posicionados después del encabezado.
+        for line in lines.by_ref() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            break;
+        }
+
+        let body: String = lines.collect::<Vec<_>>().join("");
+
+        let mut rows: Vec<Vec<u8>> = vec![Vec::new()];
+        let mut count: usize = 0;
+        for ch in body.chars() {
+            match ch {
+                '0'..='9' => {
+                    let digit = ch.to_digit(10).unwrap() as usize;
+                    count = count
+                        .checked_mul(10)
+                        .and_then(|c| c.checked_add(digit))
+                        .ok_or_else(|| {
+                            io::Error::new(io::ErrorKind::InvalidData, "contador RLE desborda")
+                        })?;
+                }
+                'b' => {
+                    let n = if count == 0 { 1 } else { count };
+                    rows.last_mut().unwrap().extend(std::iter::repeat_n(0u8, n));
+                    count = 0;
+                }
+                'o' => {
+                    let n = if count == 0 { 1 } else { count };
+                    rows.last_mut().unwrap().extend(std::iter::repeat_n(1u8, n));
+                    count = 0;
+                }
+                '$' => {
+                    let n = if count == 0 { 1 } else { count };
+                    for _ in 0..n {
+                        rows.push(Vec::new());
+                    }
+                    count = 0;
+                }
+                '!' => break,
+                _ => {} // espacios y saltos de línea dentro del stream se ignoran
+            }
+        }
+
+        for (dy, row) in rows.iter().enumerate() {
+            for (dx, &cell) in row.iter().enumerate() {
+                if cell == 1 {
+                    let x = (ox + dx) % self.w;
+                    let y = (oy + dy) % self.h;
+                    let i = self.idx(x, y);
+                    self.curr[i] = 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Guarda el estado actual en formato RLE mínimo: recorta `curr` al
+    /// bounding box de células vivas y emite el stream de tokens
+    /// correspondiente (los tramos muertos finales de cada fila se omiten,
+    /// tal como exige el formato).
+    fn save_rle(&self, path: &str) -> io::Result<()> {
+        let mut min_x = self.w;
+        let mut max_x = 0usize;
+        let mut min_y = self.h;
+        let mut max_y = 0usize;
+        let mut any_alive = false;
+
+        for y in 0..self.h {
+            for x in 0..self.w {
+                if self.curr[self.idx(x, y)] == 1 {
+                    any_alive = true;
+                    min_x = min_x.min(x);
+                    max_x = max_x.max(x);
+                    min_y = min_y.min(y);
+                    max_y = max_y.max(y);
+                }
+            }
+        }
+        if !any_alive {
+            min_x = 0;
+            max_x = 0;
+            min_y = 0;
+            max_y = 0;
+        }
+
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+
+        let mut body = String::new();
+        for y in min_y..=max_y {
+            let mut runs: Vec<(u8, usize)> = Vec::new();
+            for x in min_x..=max_x {
+                let v = self.curr[self.idx(x, y)];
+                match runs.last_mut() {
+                    Some(last) if last.0 == v => last.1 += 1,
+                    _ => runs.push((v, 1)),
+                }
+            }
+            // El tramo muerto final de la fila queda implícito, se omite.
+            if matches!(runs.last(), Some((0, _))) {
+                runs.pop();
+            }
+            for (v, n) in runs {
+                if n > 1 {
+                    body.push_str(&n.to_string());
+                }
+                body.push(if v == 1 { 'o' } else { 'b' });
+            }
+            if y != max_y {
+                body.push('$');
+            }
+        }
+        body.push('!');
+
+        let contents = format!(
+            "x = {}, y = {}, rule = {}\n{}\n",
+            width,
+            height,
+            self.rule.to_notation(),
+            body
+        );
+        fs::write(path, contents)
+    }
+
+    // ====== Estado de sesión (checkpoint) ======
+
+    /// Serializa `curr`, `w`, `h`, `delay_ms` y la regla activa a un archivo
+    /// compacto: una línea de encabezado `w,h,delay_ms,notación` seguida de
+    /// `curr` empaquetado 8 células por byte.
+    fn save_state(&self, path: &str) -> io::Result<()> {
+        let mut data = format!(
+            "{},{},{},{}\n",
+            self.w,
+            self.h,
+            self.delay_ms,
+            self.rule.to_notation()
+        )
+        .into_bytes();
+
+        for chunk in self.curr.chunks(8) {
+            let mut byte = 0u8;
+            for (i, &cell) in chunk.iter().enumerate() {
+                if cell == 1 {
+                    byte |= 1 << i;
+                }
+            }
+            data.push(byte);
+        }
+
+        fs::write(path, data)
+    }
+
+    /// Inversa de `save_state`: reconstruye un `GameOfLife` completo,
+    /// incluida la regla activa, a partir de un archivo de checkpoint.
+    fn load_state(path: &str) -> io::Result<Self> {
+        let data = fs::read(path)?;
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "estado corrupto o ilegible");
+
+        let header_end = data.iter().position(|&b| b == b'\n').ok_or_else(invalid)?;
+        let header = std::str::from_utf8(&data[..header_end]).map_err(|_| invalid())?;
+        let mut fields = header.splitn(4, ',');
+        let w: usize = fields.next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+        let h: usize = fields.next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+        let delay_ms: u64 = fields.next().and_then(|s| s.parse().ok()).ok_or_else(invalid)?;
+        let rule = fields
+            .next()
+            .and_then(Rule::parse)
+            .ok_or_else(invalid)?;
+
+        let packed = &data[header_end + 1..];
+        let mut curr = vec![0u8; w * h];
+        for (i, cell) in curr.iter_mut().enumerate() {
+            let byte = packed.get(i / 8).copied().unwrap_or(0);
+            *cell = (byte >> (i % 8)) & 1;
+        }
+
+        let mut gol = Self::new(w, h);
+        gol.curr = curr;
+        gol.delay_ms = delay_ms;
+        gol.rule = rule;
+        Ok(gol)
+    }
+}
+
+// ==================== Búsqueda genética de semillas ====================
+
+const GENOME_K: usize = 12;
+const POP_SIZE: usize = 100;
+const ELITE_FRAC: f32 = 0.1;
+const MUTATION_RATE: f32 = 0.02;
+
+// Grilla headless usada solo para evaluar genomas: chica para que evaluar
+// cien individuos por generación sea rápido.
+const EVAL_W: usize = 64;
+const EVAL_H: usize = 64;
+const EVAL_MAX_STEPS: usize = 200;
+const EVOLUTION_GENERATIONS: usize = 50;
+
+/// Un individuo: un bloque `GENOME_K x GENOME_K` de bits que se estampa en
+/// el centro de una grilla fresca al evaluarlo.
+#[derive(Clone)]
+struct Genome {
+    bits: Vec<u8>,
+}
+
+impl Genome {
+    fn random(k: usize, rng: &mut rand::rngs::ThreadRng) -> Self {
+        let bits = (0..k * k).map(|_| rng.gen_range(0..=1u8)).collect();
+        Self { bits }
+    }
+
+    /// Cruce uniforme: cada bit viene de `a` o de `b` con igual probabilidad.
+    fn crossover(a: &Genome, b: &Genome, rng: &mut rand::rngs::ThreadRng) -> Genome {
+        let bits = a
+            .bits
+            .iter()
+            .zip(b.bits.iter())
+            .map(|(&x, &y)| if rng.gen::<bool>() { x } else { y })
+            .collect();
+        Genome { bits }
+    }
+
+    fn mutate(&mut self, rate: f32, rng: &mut rand::rngs::ThreadRng) {
+        for bit in &mut self.bits {
+            if rng.gen::<f32>() < rate {
+                *bit = 1 - *bit;
+            }
+        }
+    }
+}
+
+/// Evalúa un genoma en una grilla fresca (para que ninguna corrida contamine
+/// a otra): lo estampa al centro, avanza hasta `max_steps` generaciones sin
+/// render y devuelve el pico de células vivas alcanzado. Corta temprano si
+/// el tablero se vacía o si cae en un ciclo de periodo <= 2, ya que de ahí
+/// en más el pico no puede crecer.
+fn evaluate_genome(genome: &Genome, k: usize, max_steps: usize) -> u32 {
+    let mut gol = GameOfLife::new(EVAL_W, EVAL_H);
+    let ox = (EVAL_W - k) / 2;
+    let oy = (EVAL_H - k) / 2;
+    for y in 0..k {
+        for x in 0..k {
+            if genome.bits[y * k + x] == 1 {
+                gol.set_alive(ox + x, oy + y);
+            }
+        }
+    }
+
+    let mut peak: u32 = gol.curr.iter().map(|&c| c as u32).sum();
+    let mut history: Vec<Vec<u8>> = vec![gol.curr.clone()];
+
+    for _ in 0..max_steps {
+        gol.step_no_history();
+        let alive: u32 = gol.curr.iter().map(|&c| c as u32).sum();
+        if alive == 0 {
+            break;
+        }
+        peak = peak.max(alive);
+
+        if history.len() >= 2 && gol.curr == history[history.len() - 2] {
+            break;
+        }
+        history.push(gol.curr.clone());
+        if history.len() > 2 {
+            history.remove(0);
+        }
+    }
+
+    peak
+}
+
+/// Selección por torneo de tamaño 2 sobre la población ya puntuada.
+fn tournament_select<'a>(
+    scored: &'a [(Genome, u32)],
+    rng: &mut rand::rngs::ThreadRng,
+) -> &'a Genome {
+    let i = rng.gen_range(0..scored.len());
+    let j = rng.gen_range(0..scored.len());
+    if scored[i].1 >= scored[j].1 {
+        &scored[i].0
+    } else {
+        &scored[j].0
+    }
+}
+
+/// Corre una generación completa: evalúa, ordena por fitness, conserva a
+/// los elites y llena el resto con hijos de torneo + cruce + mutación.
+/// Devuelve la nueva población junto con el mejor genoma y fitness vistos.
+fn evolve_generation(
+    pop: Vec<Genome>,
+    rng: &mut rand::rngs::ThreadRng,
+) -> (Vec<Genome>, Genome, u32) {
+    let mut scored: Vec<(Genome, u32)> = pop
+        .into_iter()
+        .map(|g| {
+            let fitness = evaluate_genome(&g, GENOME_K, EVAL_MAX_STEPS);
+            (g, fitness)
+        })
+        .collect();
+    scored.sort_by_key(|s| std::cmp::Reverse(s.1));
+
+    let best_genome = scored[0].0.clone();
+    let best_fitness = scored[0].1;
+
+    let elite_count = ((scored.len() as f32) * ELITE_FRAC).ceil() as usize;
+    let mut next_gen: Vec<Genome> = scored[..elite_count].iter().map(|(g, _)| g.clone()).collect();
+
+    while next_gen.len() < scored.len() {
+        let parent_a = tournament_select(&scored, rng);
+        let parent_b = tournament_select(&scored, rng);
+        let mut child = Genome::crossover(parent_a, parent_b, rng);
+        child.mutate(MUTATION_RATE, rng);
+        next_gen.push(child);
+    }
+
+    (next_gen, best_genome, best_fitness)
+}
+
+/// Corrida de evolución en curso, avanzada una generación por frame desde el
+/// loop principal en vez de bloquearlo: una corrida completa (100 individuos
+/// x 50 generaciones x 200 pasos headless) tarda segundos, y hacerla de un
+/// tirón dejaría la ventana sin procesar eventos ni repintar mientras tanto.
+struct EvolutionRun {
+    population: Vec<Genome>,
+    rng: rand::rngs::ThreadRng,
+    generation: usize,
+    champion: Genome,
+    best_fitness: u32,
+}
+
+impl EvolutionRun {
+    fn new() -> Self {
+        let mut rng = rand::thread_rng();
+        let population: Vec<Genome> = (0..POP_SIZE).map(|_| Genome::random(GENOME_K, &mut rng)).collect();
+        let champion = population[0].clone();
+        Self {
+            population,
+            rng,
+            generation: 0,
+            champion,
+            best_fitness: 0,
+        }
+    }
+
+    /// Corre una única generación. Devuelve `true` cuando la corrida ya
+    /// completó todas las generaciones.
+    fn step(&mut self) -> bool {
+        let pop = std::mem::take(&mut self.population);
+        let (next_gen, best_genome, best_fitness) = evolve_generation(pop, &mut self.rng);
+        println!("Generación {}: mejor fitness = {}", self.generation, best_fitness);
+
+        if best_fitness > self.best_fitness {
+            self.best_fitness = best_fitness;
+            self.champion = best_genome;
+        }
+        self.population = next_gen;
+        self.generation += 1;
+
+        self.generation >= EVOLUTION_GENERATIONS
+    }
 }
 
 // ==================== Main / Engine ====================
 
 fn main() {
     let mut window = Window::new(
-        "Conway's Game of Life (Rust) - Space: pausa | N: step | R: random | C: clear | 1/2/3: velocidad | Esc: salir",
+        "Conway's Game of Life (Rust) - Space: pausa | N: step | R: random | C: clear | 1/2/3: velocidad | L: regla | Flechas: pan | +/-: zoom | G: evolucionar | B: sembrar mejor | Z: deshacer | F5: guardar | F9: cargar | F6: guardar RLE | F7: cargar RLE | Click izq/der: pintar/borrar | Esc: salir",
         GRID_W,
         GRID_H,
         WindowOptions {
@@ -410,36 +958,64 @@ fn main() {
     .expect("No se pudo crear la ventana");
 
     let mut fb = Framebuffer::new(GRID_W, GRID_H);
-    let mut gol = GameOfLife::new(GRID_W, GRID_H);
 
-    // ------------ Patrón inicial creativo (mezcla) ------------
-    // Still lifes
-    gol.spawn_block(5, 5);
-    gol.spawn_beehive(15, 5);
-    gol.spawn_loaf(30, 5);
-    gol.spawn_boat(45, 5);
-    gol.spawn_tub(60, 5);
+    // Si hay un checkpoint de una sesión anterior, se restaura en vez de
+    // sembrar algo nuevo: así no se pierde trabajo entre corridas. Si no hay
+    // checkpoint pero sí un `.rle` por defecto, se importa ese patrón; solo
+    // si ninguno de los dos existe se cae a la demo hardcodeada.
+    let mut gol = match GameOfLife::load_state(DEFAULT_STATE_PATH) {
+        Ok(restored) => restored,
+        Err(_) => {
+            let mut fresh = GameOfLife::new(SIM_W, SIM_H);
 
-    // Oscillators
-    gol.spawn_blinker(10, 30);
-    gol.spawn_toad(20, 30);
-    gol.spawn_beacon(30, 28);
-    gol.spawn_pulsar(60, 25);
-    gol.spawn_pentadecathlon(90, 15);
+            if fresh
+                .load_rle(RLE_SPAWN_OX, RLE_SPAWN_OY, DEFAULT_RLE_PATH)
+                .is_err()
+            {
+                // ------------ Patrón inicial creativo (mezcla) ------------
+                // Still lifes
+                fresh.spawn_block(5, 5);
+                fresh.spawn_beehive(15, 5);
+                fresh.spawn_loaf(30, 5);
+                fresh.spawn_boat(45, 5);
+                fresh.spawn_tub(60, 5);
 
-    // Spaceships
-    gol.spawn_glider(5, 80);
-    gol.spawn_lwss(20, 85);
-    gol.spawn_mwss(40, 85);
-    gol.spawn_hwss(70, 85);
+                // Oscillators
+                fresh.spawn_blinker(10, 30);
+                fresh.spawn_toad(20, 30);
+                fresh.spawn_beacon(30, 28);
+                fresh.spawn_pulsar(60, 25);
+                fresh.spawn_pentadecathlon(90, 15);
 
-    // Extras que “rompen” la pantalla con el tiempo
-    gol.spawn_r_pentomino(100, 60);
-    gol.spawn_diehard(5, 100);
-    gol.spawn_acorn(80, 90);
-    // -----------------------------------------------------------
+                // Spaceships
+                fresh.spawn_glider(5, 80);
+                fresh.spawn_lwss(20, 85);
+                fresh.spawn_mwss(40, 85);
+                fresh.spawn_hwss(70, 85);
+
+                // Extras que “rompen” la pantalla con el tiempo
+                fresh.spawn_r_pentomino(100, 60);
+                fresh.spawn_diehard(5, 100);
+                fresh.spawn_acorn(80, 90);
+                // -----------------------------------------------------------
+            }
+
+            fresh
+        }
+    };
 
     let mut last_step = Instant::now();
+    let mut rule_idx = 0usize;
+
+    // Cámara: (cam_x, cam_y) es la celda del mundo en la esquina superior
+    // izquierda de la ventana; zoom es el tamaño en píxeles de cada celda.
+    let mut cam_x = 0usize;
+    let mut cam_y = 0usize;
+    let mut zoom = 1usize;
+    const PAN_STEP: usize = 5;
+
+    let mut best_genome: Option<Genome> = None;
+    let mut evolution_run: Option<EvolutionRun> = None;
 
     while window.is_open() && !window.is_key_down(Key::Escape) {
         // Input
@@ -464,6 +1040,106 @@ fn main() {
         if window.is_key_pressed(Key::Key3, minifb::KeyRepeat::No) {
             gol.delay_ms = 16;
         }
+        if window.is_key_pressed(Key::L, minifb::KeyRepeat::No) {
+            rule_idx = (rule_idx + 1) % RULE_PRESETS.len();
+            let (name, notation) = RULE_PRESETS[rule_idx];
+            gol.rule = Rule::parse(notation).expect("preset de regla inválido");
+            println!("Regla: {name} ({notation})");
+        }
+
+        // Pan con flechas (envuelve en el toroide) y zoom entero con +/-.
+        if window.is_key_down(Key::Left) {
+            cam_x = (cam_x + gol.w - PAN_STEP) % gol.w;
+        }
+        if window.is_key_down(Key::Right) {
+            cam_x = (cam_x + PAN_STEP) % gol.w;
+        }
+        if window.is_key_down(Key::Up) {
+            cam_y = (cam_y + gol.h - PAN_STEP) % gol.h;
+        }
+        if window.is_key_down(Key::Down) {
+            cam_y = (cam_y + PAN_STEP) % gol.h;
+        }
+        if window.is_key_pressed(Key::Equal, minifb::KeyRepeat::No) && zoom < ZOOM_MAX {
+            zoom += 1;
+        }
+        if window.is_key_pressed(Key::Minus, minifb::KeyRepeat::No) && zoom > ZOOM_MIN {
+            zoom -= 1;
+        }
+
+        // Búsqueda genética de semillas: G arranca la evolución (se
+        // adelanta una generación por frame para no congelar la ventana) y
+        // B estampa el mejor genoma encontrado en la grilla viva, cerca de
+        // la cámara.
+        if window.is_key_pressed(Key::G, minifb::KeyRepeat::No) {
+            evolution_run = Some(EvolutionRun::new());
+        }
+        if let Some(run) = &mut evolution_run {
+            if run.step() {
+                best_genome = Some(run.champion.clone());
+                evolution_run = None;
+            }
+        }
+        if window.is_key_pressed(Key::B, minifb::KeyRepeat::No) {
+            if let Some(genome) = &best_genome {
+                let ox = (cam_x + 10) % gol.w;
+                let oy = (cam_y + 10) % gol.h;
+                for y in 0..GENOME_K {
+                    for x in 0..GENOME_K {
+                        if genome.bits[y * GENOME_K + x] == 1 {
+                            gol.set_alive((ox + x) % gol.w, (oy + y) % gol.h);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Checkpoint de sesión: F5 guarda, F9 recarga; Z deshace un paso
+        // sacando el estado anterior del historial en vez de avanzar.
+        if window.is_key_pressed(Key::F5, minifb::KeyRepeat::No) {
+            if let Err(e) = gol.save_state(DEFAULT_STATE_PATH) {
+                eprintln!("No se pudo guardar el estado: {e}");
+            }
+        }
+        if window.is_key_pressed(Key::F9, minifb::KeyRepeat::No) {
+            match GameOfLife::load_state(DEFAULT_STATE_PATH) {
+                Ok(restored) => gol = restored,
+                Err(e) => eprintln!("No se pudo cargar el estado: {e}"),
+            }
+        }
+        if window.is_key_pressed(Key::Z, minifb::KeyRepeat::No) {
+            gol.step_back();
+        }
+
+        // Patrones RLE: F6 exporta el patrón actual, F7 importa el default.
+        if window.is_key_pressed(Key::F6, minifb::KeyRepeat::No) {
+            if let Err(e) = gol.save_rle(DEFAULT_RLE_PATH) {
+                eprintln!("No se pudo guardar el RLE: {e}");
+            }
+        }
+        if window.is_key_pressed(Key::F7, minifb::KeyRepeat::No) {
+            if let Err(e) = gol.load_rle(RLE_SPAWN_OX, RLE_SPAWN_OY, DEFAULT_RLE_PATH) {
+                eprintln!("No se pudo cargar el RLE: {e}");
+            }
+        }
+
+        // Pintar/borrar células arrastrando el mouse (también mientras está en pausa).
+        // Usa la posición sin escalar: `get_mouse_pos` ya divide por
+        // WINDOW_SCALE, y volver a dividir por win_w/grid_w (que también
+        // incluye ese mismo factor) pintaría la celda equivocada.
+        if let Some((mx, my)) = window.get_unscaled_mouse_pos(MouseMode::Pass) {
+            let (win_w, win_h) = window.get_size();
+            if let Some((fx, fy)) = xy_to_world(mx, my, win_w, win_h, GRID_W, GRID_H) {
+                let wx = (cam_x + fx / zoom) % gol.w;
+                let wy = (cam_y + fy / zoom) % gol.h;
+                if window.get_mouse_down(MouseButton::Left) {
+                    gol.set_alive(wx, wy);
+                }
+                if window.get_mouse_down(MouseButton::Right) {
+                    gol.set_dead(wx, wy);
+                }
+            }
+        }
 
         // Update (step) con timing simple
         let should_step = if gol.paused {
@@ -479,7 +1155,7 @@ fn main() {
         }
 
         // Render
-        gol.render_to(&mut fb);
+        gol.render_to(&mut fb, cam_x, cam_y, zoom);
 
         window
             .update_with_buffer(&fb.buf, fb.w, fb.h)
@@ -491,3 +1167,23 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xy_to_world_divides_out_the_full_window_to_grid_ratio() {
+        // Ventana al doble del tamaño de la grilla (p. ej. WINDOW_SCALE X2 sin
+        // redimensionar): el píxel sin escalar 100 debe caer en la celda 50.
+        assert_eq!(
+            xy_to_world(100.0, 100.0, GRID_W * 2, GRID_H * 2, GRID_W, GRID_H),
+            Some((50, 50))
+        );
+    }
+
+    #[test]
+    fn xy_to_world_rejects_out_of_bounds_positions() {
+        assert_eq!(xy_to_world(-1.0, 0.0, GRID_W, GRID_H, GRID_W, GRID_H), None);
+    }
+}